@@ -0,0 +1,62 @@
+//! Batch translation over many segments.
+//!
+//! Segments are split into chunks and each chunk is translated with a single
+//! `TextTranslateBatch` call. Chunks are fired concurrently, bounded by a
+//! semaphore so a large input does not open an unbounded number of
+//! connections at once.
+
+use crate::client::TencentCloudClient;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Segments sent to the API in a single `TextTranslateBatch` call.
+const CHUNK_SIZE: usize = 10;
+/// Maximum number of batch requests in flight at once.
+const MAX_IN_FLIGHT: usize = 5;
+
+/// Translate `segments` from `source` to `target`, preserving input order.
+pub async fn translate(
+    client: Arc<TencentCloudClient>,
+    segments: Vec<String>,
+    source: String,
+    target: String,
+) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+    let semaphore = Arc::new(Semaphore::new(MAX_IN_FLIGHT));
+    let mut handles = Vec::new();
+
+    for (idx, chunk) in segments.chunks(CHUNK_SIZE).enumerate() {
+        let chunk = chunk.to_vec();
+        let client = Arc::clone(&client);
+        let semaphore = Arc::clone(&semaphore);
+        let source = source.clone();
+        let target = target.clone();
+
+        handles.push(tokio::spawn(async move {
+            let _permit = semaphore.acquire().await.expect("semaphore closed");
+            let payload = json!({
+                "Source": source,
+                "Target": target,
+                "ProjectId": 0,
+                "SourceTextList": chunk,
+            });
+            let res = client.call("TextTranslateBatch", &payload).await?;
+            let list = res["Response"]["TargetTextList"]
+                .as_array()
+                .ok_or_else(|| format!("Api response error! Response: {:?}", res))?
+                .iter()
+                .map(|v| v.as_str().unwrap_or_default().to_string())
+                .collect::<Vec<_>>();
+            Ok::<(usize, Vec<String>), Box<dyn std::error::Error + Send + Sync>>((idx, list))
+        }));
+    }
+
+    // Reassemble the chunks in their original order.
+    let mut chunks: Vec<(usize, Vec<String>)> = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let (idx, list) = handle.await??;
+        chunks.push((idx, list));
+    }
+    chunks.sort_by_key(|(idx, _)| *idx);
+    Ok(chunks.into_iter().flat_map(|(_, list)| list).collect())
+}
@@ -0,0 +1,208 @@
+use reqwest::Client;
+use ring::hmac;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn sign(key: &[u8], msg: &[u8]) -> Vec<u8> {
+    let s_key = hmac::Key::new(hmac::HMAC_SHA256, key);
+    hmac::sign(&s_key, msg).as_ref().to_vec()
+}
+
+/// Maximum number of times a retryable error is retried.
+const MAX_RETRIES: u32 = 3;
+
+/// A minimal Tencent Cloud API v3 client built around the `TC3-HMAC-SHA256`
+/// signature flow. It is intentionally service-agnostic: `tmt`, `ocr` or any
+/// other endpoint can be driven through the same [`TencentCloudClient::call`]
+/// by passing the matching `action` and JSON `payload`.
+pub struct TencentCloudClient {
+    secret_id: String,
+    secret_key: String,
+    service: String,
+    host: String,
+    region: String,
+    version: String,
+    token: Option<String>,
+    client: Client,
+    /// Offset in seconds added to the local clock to compensate for drift
+    /// against the server, learned from a `SignatureExpire` response.
+    clock_skew: AtomicI64,
+}
+
+impl TencentCloudClient {
+    pub fn new(
+        secret_id: String,
+        secret_key: String,
+        service: String,
+        host: String,
+        region: String,
+        version: String,
+        token: Option<String>,
+    ) -> Self {
+        Self {
+            secret_id,
+            secret_key,
+            service,
+            host,
+            region,
+            version,
+            token,
+            client: Client::new(),
+            clock_skew: AtomicI64::new(0),
+        }
+    }
+
+    /// Invoke `action` with `payload` and return the whole parsed JSON body
+    /// (the result sits under its `Response` key).
+    ///
+    /// Retries with exponential backoff on `RequestLimitExceeded`, and on
+    /// `AuthFailure.SignatureExpire` re-signs against the server's own clock
+    /// (read from the `Date` response header) so a drifted local clock still
+    /// succeeds.
+    pub async fn call(
+        &self,
+        action: &str,
+        payload: &serde_json::Value,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error + Send + Sync>> {
+        let mut attempt = 0u32;
+        loop {
+            let (res, server_ts) = self.send_once(action, payload).await?;
+            let code = res["Response"]["Error"]["Code"].as_str();
+
+            match code {
+                Some("AuthFailure.SignatureExpire") if attempt < MAX_RETRIES => {
+                    // Re-sync the clock from the server and sign again.
+                    if let Some(server_ts) = server_ts {
+                        let local = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+                        self.clock_skew.store(server_ts - local, Ordering::Relaxed);
+                    }
+                    attempt += 1;
+                }
+                Some("RequestLimitExceeded") if attempt < MAX_RETRIES => {
+                    let backoff = Duration::from_millis(200 * 2u64.pow(attempt));
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                _ => return Ok(res),
+            }
+        }
+    }
+
+    /// Sign and send a single request, returning the parsed body and the
+    /// server timestamp parsed from the `Date` response header (if any).
+    async fn send_once(
+        &self,
+        action: &str,
+        payload: &serde_json::Value,
+    ) -> Result<(serde_json::Value, Option<i64>), Box<dyn std::error::Error + Send + Sync>> {
+        let algorithm = "TC3-HMAC-SHA256";
+
+        let skew = self.clock_skew.load(Ordering::Relaxed);
+        let timestamp =
+            (SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64 + skew) as u64;
+        let date = chrono::DateTime::from_timestamp(timestamp as i64, 0)
+            .unwrap()
+            .format("%Y-%m-%d")
+            .to_string();
+
+        // Step 1: Create Canonical Request
+        let http_request_method = "POST";
+        let canonical_uri = "/";
+        let canonical_querystring = "";
+        let ct = "application/json; charset=utf-8";
+        let canonical_headers = format!(
+            "content-type:{}\nhost:{}\nx-tc-action:{}\n",
+            ct,
+            self.host,
+            action.to_lowercase()
+        );
+        let signed_headers = "content-type;host;x-tc-action";
+        let hashed_request_payload =
+            ring::digest::digest(&ring::digest::SHA256, payload.to_string().as_bytes());
+        let payload_hash = hex::encode(hashed_request_payload);
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            http_request_method,
+            canonical_uri,
+            canonical_querystring,
+            canonical_headers,
+            signed_headers,
+            payload_hash,
+        );
+
+        // Step 2: Create String to Sign
+        let credential_scope = format!("{}/{}/tc3_request", date, self.service);
+        let hashed_canonical_request =
+            ring::digest::digest(&ring::digest::SHA256, canonical_request.as_bytes());
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            algorithm,
+            timestamp,
+            credential_scope,
+            hex::encode(hashed_canonical_request)
+        );
+
+        // Step 3: Calculate Signature
+        let secret_date = sign(
+            format!("TC3{}", self.secret_key).as_bytes(),
+            date.as_bytes(),
+        );
+        let secret_service = sign(&secret_date, self.service.as_bytes());
+        let secret_signing = sign(&secret_service, b"tc3_request");
+        let signature = hmac::sign(
+            &hmac::Key::new(hmac::HMAC_SHA256, &secret_signing),
+            string_to_sign.as_bytes(),
+        );
+
+        // Step 4: Create Authorization
+        let authorization = format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            algorithm,
+            self.secret_id,
+            credential_scope,
+            signed_headers,
+            hex::encode(signature)
+        );
+
+        // Step 5: Send Request
+        let mut headers = HashMap::new();
+        headers.insert("Authorization", authorization);
+        headers.insert("Content-Type", ct.to_string());
+        headers.insert("Host", self.host.clone());
+        headers.insert("X-TC-Action", action.to_string());
+        headers.insert("X-TC-Timestamp", timestamp.to_string());
+        headers.insert("X-TC-Version", self.version.clone());
+        if !self.region.is_empty() {
+            headers.insert("X-TC-Region", self.region.clone());
+        }
+        if let Some(token) = &self.token {
+            headers.insert("X-TC-Token", token.clone());
+        }
+
+        let endpoint = format!("https://{}", self.host);
+        let response = self
+            .client
+            .post(&endpoint)
+            .headers(reqwest::header::HeaderMap::from_iter(
+                headers
+                    .iter()
+                    .map(|(k, v)| (k.parse().unwrap(), v.parse().unwrap())),
+            ))
+            .json(payload)
+            .send()
+            .await?;
+
+        // Grab the server's clock from the `Date` header before the body is
+        // consumed, so a skew error can be corrected on the next attempt.
+        let server_ts = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| chrono::DateTime::parse_from_rfc2822(s).ok())
+            .map(|dt| dt.timestamp());
+
+        let res = response.json::<serde_json::Value>().await?;
+        Ok((res, server_ts))
+    }
+}
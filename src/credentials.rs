@@ -0,0 +1,44 @@
+//! Credential resolution.
+//!
+//! Credentials are looked up in order of precedence so the tool works both
+//! with long-lived keys and with the temporary credentials handed out to a CAM
+//! role or an SCF function URL:
+//!
+//! 1. explicit CLI flags (`--secret-id` / `--secret-key` / `--token`)
+//! 2. the standard `TENCENTCLOUD_SECRET_ID` / `_KEY` / `_TOKEN` env vars
+//! 3. this tool's original `TENCENT_TRANSLATION_SECRET_ID` / `_KEY` / `_TOKEN`
+
+use std::env;
+
+pub struct Credentials {
+    pub secret_id: String,
+    pub secret_key: String,
+    pub token: Option<String>,
+}
+
+/// Resolve credentials from flags then the environment, erroring only when the
+/// id or key cannot be found anywhere. A session `token` is optional and, when
+/// present, is later sent as the `X-TC-Token` header.
+pub fn resolve(
+    flag_secret_id: Option<String>,
+    flag_secret_key: Option<String>,
+    flag_token: Option<String>,
+) -> Result<Credentials, Box<dyn std::error::Error + Send + Sync>> {
+    let secret_id = flag_secret_id
+        .or_else(|| env::var("TENCENTCLOUD_SECRET_ID").ok())
+        .or_else(|| env::var("TENCENT_TRANSLATION_SECRET_ID").ok())
+        .ok_or("Please set TENCENTCLOUD_SECRET_ID (or pass --secret-id)")?;
+    let secret_key = flag_secret_key
+        .or_else(|| env::var("TENCENTCLOUD_SECRET_KEY").ok())
+        .or_else(|| env::var("TENCENT_TRANSLATION_SECRET_KEY").ok())
+        .ok_or("Please set TENCENTCLOUD_SECRET_KEY (or pass --secret-key)")?;
+    let token = flag_token
+        .or_else(|| env::var("TENCENTCLOUD_TOKEN").ok())
+        .or_else(|| env::var("TENCENT_TRANSLATION_TOKEN").ok());
+
+    Ok(Credentials {
+        secret_id,
+        secret_key,
+        token,
+    })
+}
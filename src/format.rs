@@ -0,0 +1,139 @@
+//! Output formatters.
+//!
+//! Each [`OutputFormatter`] renders one or more [`Translation`]s into a string
+//! ready to print, so the tool can feed HTML viewers, launcher workflows,
+//! JSON pipelines or plain shell scripts from the same result.
+
+use serde_json::json;
+
+const CSS: &str = r#"<style type="text/css">
+.engine {
+  font-family: "MiSansVF";
+  font-size: 18px;
+  color: #578bc5;
+}
+.originalText {
+    font-size: 120%;
+    font-family: "MiSansVF";
+    font-weight: 600;
+    display: inline-block;
+    margin: 0rem 0rem 0rem 0rem;
+    color: #2a5598;
+    margin-bottom: 0.6rem;
+}
+.frame {
+    margin: 1rem 0.5rem 0.5rem 0;
+    padding: 0.7rem 0.5rem 0.5rem 0;
+    border-top: 3px dashed #eaeef6;
+}
+definition {
+    font-family: "MiSansVF";
+    color: #2a5598;
+    height: 120px;
+    padding: 0.05em;
+    font-weight: 500;
+    font-size: 16px;
+}
+</style>"#;
+
+/// A single source → target result together with the language pair it used.
+pub struct Translation {
+    pub source: String,
+    pub target: String,
+    pub source_lang: String,
+    pub target_lang: String,
+}
+
+pub trait OutputFormatter {
+    fn format(&self, items: &[Translation]) -> String;
+}
+
+/// Resolve the `--format` value to a formatter; any name other than `html`,
+/// `plain`, `json` or `markdown` is an error.
+pub fn formatter(
+    name: &str,
+) -> Result<Box<dyn OutputFormatter>, Box<dyn std::error::Error + Send + Sync>> {
+    match name {
+        "html" => Ok(Box::new(Html)),
+        "plain" => Ok(Box::new(Plain)),
+        "json" => Ok(Box::new(Json)),
+        "markdown" => Ok(Box::new(Markdown)),
+        other => Err(format!("Unknown format '{}' (want html|plain|json|markdown)", other).into()),
+    }
+}
+
+/// Today's styled HTML card.
+pub struct Html;
+
+impl OutputFormatter for Html {
+    fn format(&self, items: &[Translation]) -> String {
+        let mut out = String::from(CSS);
+        for item in items {
+            out.push_str(&format!(
+                "\n<div class=\"originalText\">{}</div>",
+                item.source
+            ));
+            out.push_str("\n<div class=\"frame\">");
+            out.push_str(&format!("\n<definition>{}</definition>", item.target));
+            out.push_str("\n</div>");
+        }
+        out.push_str("\n<br>");
+        out
+    }
+}
+
+/// Just the translated text, one line per segment.
+pub struct Plain;
+
+impl OutputFormatter for Plain {
+    fn format(&self, items: &[Translation]) -> String {
+        items
+            .iter()
+            .map(|i| i.target.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Machine-readable JSON: an object for a single segment, an array otherwise.
+pub struct Json;
+
+impl OutputFormatter for Json {
+    fn format(&self, items: &[Translation]) -> String {
+        let objs = items
+            .iter()
+            .map(|i| {
+                json!({
+                    "source": i.source,
+                    "target": i.target,
+                    "source_lang": i.source_lang,
+                    "target_lang": i.target_lang,
+                })
+            })
+            .collect::<Vec<_>>();
+        let value = if objs.len() == 1 {
+            objs.into_iter().next().unwrap()
+        } else {
+            serde_json::Value::Array(objs)
+        };
+        serde_json::to_string_pretty(&value).unwrap_or_default()
+    }
+}
+
+/// A fenced Markdown block per segment.
+pub struct Markdown;
+
+impl OutputFormatter for Markdown {
+    fn format(&self, items: &[Translation]) -> String {
+        items
+            .iter()
+            .map(|i| {
+                format!(
+                    "**{}** → **{}**\n\n```\n{}\n```",
+                    i.source_lang, i.target_lang, i.target
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
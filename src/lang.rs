@@ -0,0 +1,122 @@
+//! Language detection helpers.
+//!
+//! The authoritative source is the Tencent `LanguageDetect` action (see
+//! [`detect`]); the functions here are the cheap offline fallback used when
+//! that call cannot be reached.
+
+use crate::client::TencentCloudClient;
+use serde_json::json;
+
+/// Scripts we can recognise locally without a network round-trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Script {
+    Chinese,
+    Japanese,
+    Korean,
+    Latin,
+    Other,
+}
+
+/// Classify a single character into a [`Script`].
+pub fn classify(ch: char) -> Script {
+    if is_chinese(ch) {
+        Script::Chinese
+    } else if is_japanese(ch) {
+        Script::Japanese
+    } else if is_korean(ch) {
+        Script::Korean
+    } else if ch.is_ascii_alphabetic() {
+        Script::Latin
+    } else {
+        Script::Other
+    }
+}
+
+pub fn is_chinese(ch: char) -> bool {
+    // 判断字符是否在中文范围内
+    match ch {
+        '\u{4E00}'..='\u{9FFF}' | // 常用汉字
+        '\u{3400}'..='\u{4DBF}' | // 扩展A区
+        '\u{20000}'..='\u{2A6DF}' | // 扩展B区
+        '\u{2A700}'..='\u{2B73F}' | // 扩展C区
+        '\u{2B740}'..='\u{2B81F}' | // 扩展D区
+        '\u{2B820}'..='\u{2CEAF}' | // 扩展E区
+        '\u{F900}'..='\u{FAFF}' | // 兼容汉字
+        '\u{2F800}'..='\u{2FA1F}' => true, // 兼容汉字扩展
+        _ => false,
+    }
+}
+
+pub fn is_japanese(ch: char) -> bool {
+    // 平假名与片假名
+    matches!(ch, '\u{3040}'..='\u{30FF}')
+}
+
+pub fn is_korean(ch: char) -> bool {
+    // 谚文音节
+    matches!(ch, '\u{AC00}'..='\u{D7AF}')
+}
+
+/// Guess the dominant source language of `text` offline, returning a TMT
+/// language code (`"zh"`, `"ja"`, `"ko"` or `"en"`). Kana takes priority over
+/// Han so that mixed Japanese text is not mistaken for Chinese.
+pub fn guess_source(text: &str) -> &'static str {
+    let mut chinese = 0usize;
+    let mut japanese = 0usize;
+    let mut korean = 0usize;
+    let mut latin = 0usize;
+
+    for ch in text.chars() {
+        match classify(ch) {
+            Script::Chinese => chinese += 1,
+            Script::Japanese => japanese += 1,
+            Script::Korean => korean += 1,
+            Script::Latin => latin += 1,
+            Script::Other => {}
+        }
+    }
+
+    if japanese > 0 {
+        "ja"
+    } else if korean > 0 {
+        "ko"
+    } else if chinese > latin {
+        "zh"
+    } else {
+        "en"
+    }
+}
+
+/// Normalise a language code coming from another service (e.g. OCR reports
+/// `"jap"`/`"kor"`) to the TMT code the translate action expects.
+pub fn normalize(code: &str) -> &str {
+    match code {
+        "jap" => "ja",
+        "kor" => "ko",
+        "eng" => "en",
+        other => other,
+    }
+}
+
+/// Pick a sensible target for a detected source: Chinese rounds to English,
+/// everything else rounds to Chinese.
+pub fn default_target(source: &str) -> &'static str {
+    if source == "zh" {
+        "en"
+    } else {
+        "zh"
+    }
+}
+
+/// Ask the TMT `LanguageDetect` action which language `text` is written in.
+/// Falls back to [`guess_source`] if the call fails or returns nothing.
+pub async fn detect(client: &TencentCloudClient, text: &str) -> String {
+    let payload = json!({ "Text": text, "ProjectId": 0 });
+    match client.call("LanguageDetect", &payload).await {
+        Ok(res) => res["Response"]["Lang"]
+            .as_str()
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| guess_source(text).to_string()),
+        Err(_) => guess_source(text).to_string(),
+    }
+}
@@ -0,0 +1,44 @@
+//! Image text extraction via Tencent Cloud OCR.
+//!
+//! [`extract`] runs `GeneralBasicOCR` over a local image and returns the
+//! recognised lines together with the language OCR reports, which can seed the
+//! translation target. It reuses the shared [`TencentCloudClient`] signer,
+//! pointed at the `ocr` service rather than `tmt`.
+
+use crate::client::TencentCloudClient;
+use base64::Engine;
+use serde_json::json;
+
+/// What OCR recovered from an image.
+pub struct Recognized {
+    /// One entry per detected text block, in reading order.
+    pub lines: Vec<String>,
+    /// Language OCR reports for the image, if any (e.g. `"zh"`, `"jap"`).
+    pub language: Option<String>,
+}
+
+/// The `ocr` service parameters, mirroring the `tmt` ones the binary hardcodes.
+pub const SERVICE: &str = "ocr";
+pub const HOST: &str = "ocr.tencentcloudapi.com";
+pub const VERSION: &str = "2018-11-19";
+
+/// Run `GeneralBasicOCR` over the image at `path` and return its text blocks.
+pub async fn extract(
+    client: &TencentCloudClient,
+    path: &str,
+) -> Result<Recognized, Box<dyn std::error::Error + Send + Sync>> {
+    let bytes = std::fs::read(path)?;
+    let image_base64 = base64::engine::general_purpose::STANDARD.encode(bytes);
+    let payload = json!({ "ImageBase64": image_base64 });
+    let res = client.call("GeneralBasicOCR", &payload).await?;
+
+    let lines = res["Response"]["TextDetections"]
+        .as_array()
+        .ok_or_else(|| format!("Api response error! Response: {:?}", res))?
+        .iter()
+        .filter_map(|d| d["DetectedText"].as_str().map(|s| s.to_string()))
+        .collect();
+    let language = res["Response"]["Language"].as_str().map(|s| s.to_string());
+
+    Ok(Recognized { lines, language })
+}